@@ -11,6 +11,9 @@ fn plot<const N: usize>(
     params: &[f32; N],
 ) -> Result<(), Box<dyn std::error::Error>> {
     let ar = Autoregressive::new(0.0, 1.0, params);
+    if ar.is_stationary() == Some(false) {
+        eprintln!("warning: {caption} is not stationary, values may drift or explode");
+    }
     let data: Vec<(usize, f32)> = ar.enumerate().take(NUM_SAMPLES).collect();
     let (min, max) = data
         .iter()
@@ -16,44 +16,271 @@
 ///
 pub mod univariate {
     use num_traits::Float;
+    use rand::{Rng, RngCore};
     use rand_distr::{Distribution, StandardNormal};
 
-    pub struct Autoregressive<F, const N: usize>
+    /// The default RNG used by [`Autoregressive::new`], matching the crate's historical
+    /// (non-reproducible) behavior.
+    pub type DefaultRng = rand::rngs::ThreadRng;
+
+    pub struct Autoregressive<F, const N: usize, D = rand_distr::Normal<F>, R = DefaultRng>
     where
         F: Float,
-        StandardNormal: Distribution<F>,
+        D: Distribution<F>,
     {
         c: F,
         x: [F; N],
         phi: [F; N],
-        noise: rand_distr::Normal<F>,
+        noise: D,
+        rng: R,
     }
 
-    impl<F, const N: usize> Autoregressive<F, N>
+    impl<F, const N: usize> Autoregressive<F, N, rand_distr::Normal<F>, DefaultRng>
     where
         F: Float + std::iter::Sum,
         StandardNormal: Distribution<F>,
     {
-        /// Create a new instance
+        /// Create a new instance, sampling Gaussian innovations from the thread-local RNG.
         /// * `c`  parameter
         /// * `noise_variance` Variance of the white noise (epsilon)
         /// * `phi` model parameters
         pub fn new(c: F, noise_variance: F, phi: &[F; N]) -> Self {
-            let x = [num_traits::identities::zero(); N];
+            Self::new_with_rng(c, noise_variance, phi, rand::thread_rng())
+        }
+
+        /// Estimate an order-`N` model from an observed series using the Levinson-Durbin
+        /// recursion on the sample autocovariances.
+        ///
+        /// Returns [`FitError::InsufficientData`] if `data` is too short to estimate `N`
+        /// lags, and [`FitError::Degenerate`] if the series is (numerically) constant or
+        /// estimation otherwise becomes singular.
+        pub fn fit(data: &[F]) -> Result<Self, FitError> {
+            if data.len() <= N {
+                return Err(FitError::InsufficientData);
+            }
+            let n = F::from(data.len()).unwrap();
+            let mean = data.iter().copied().sum::<F>() / n;
+
+            let mut r = Vec::with_capacity(N + 1);
+            for k in 0..=N {
+                let mut sum = F::zero();
+                for t in k..data.len() {
+                    sum = sum + (data[t] - mean) * (data[t - k] - mean);
+                }
+                r.push(sum / n);
+            }
+
+            if r[0] <= F::zero() {
+                return Err(FitError::Degenerate);
+            }
+
+            let mut phi = vec![F::zero(); N];
+            let mut e = r[0];
+
+            for i in 1..=N {
+                if e <= F::zero() {
+                    return Err(FitError::Degenerate);
+                }
+                let mut acc = r[i];
+                for j in 1..i {
+                    acc = acc - phi[j - 1] * r[i - j];
+                }
+                let k = acc / e;
+
+                let mut new_phi = phi.clone();
+                new_phi[i - 1] = k;
+                for j in 1..i {
+                    new_phi[j - 1] = phi[j - 1] - k * phi[i - j - 1];
+                }
+                phi = new_phi;
+                e = e * (F::one() - k * k);
+            }
+
+            if e <= F::zero() {
+                return Err(FitError::Degenerate);
+            }
+
+            let phi_sum = phi.iter().copied().sum::<F>();
+            let c = mean * (F::one() - phi_sum);
+
+            let mut phi_arr = [F::zero(); N];
+            phi_arr.copy_from_slice(&phi);
+
+            // `e` is the innovation variance; `new` takes its standard deviation.
+            Ok(Self::new(c, e.sqrt(), &phi_arr))
+        }
+
+        /// Bootstrap confidence intervals for the `phi` coefficients of an order-`N` fit
+        /// on `data`, via residual resampling.
+        ///
+        /// Fits once to recover `c`, `phi`, and the in-sample residuals, then `nresamples`
+        /// times regenerates a synthetic series by re-running the recurrence while drawing
+        /// innovations uniformly with replacement from the residual pool, re-fits on each
+        /// synthetic series, and reports the `(1-confidence)/2` and `1-(1-confidence)/2`
+        /// empirical percentiles of each coefficient's bootstrap distribution. `rng` drives
+        /// the resampling, so the result is reproducible for a fixed seed.
+        pub fn bootstrap_ci<R: RngCore>(
+            data: &[F],
+            nresamples: usize,
+            confidence: F,
+            rng: &mut R,
+        ) -> Result<[(F, F); N], FitError> {
+            let fitted = Self::fit(data)?;
+            let (c, phi) = fitted.params();
+            let phi = *phi;
+
+            let residuals: Vec<F> = (N..data.len())
+                .map(|t| {
+                    let pred = c
+                        + (0..N)
+                            .map(|i| phi[i] * data[t - 1 - i])
+                            .sum::<F>();
+                    data[t] - pred
+                })
+                .collect();
+
+            let mut replicates: Vec<[F; N]> = Vec::with_capacity(nresamples);
+            for _ in 0..nresamples {
+                let mut synthetic = Vec::with_capacity(data.len());
+                synthetic.extend_from_slice(&data[..N]);
+                for t in N..data.len() {
+                    let pred = c
+                        + (0..N)
+                            .map(|i| phi[i] * synthetic[t - 1 - i])
+                            .sum::<F>();
+                    let idx = rng.gen_range(0..residuals.len());
+                    synthetic.push(pred + residuals[idx]);
+                }
+                if let Ok(refit) = Self::fit(&synthetic) {
+                    replicates.push(*refit.params().1);
+                }
+            }
+
+            if replicates.is_empty() {
+                return Err(FitError::Degenerate);
+            }
+
+            let alpha = (F::one() - confidence) / (F::one() + F::one());
+            let mut intervals = [(F::zero(), F::zero()); N];
+            for (i, interval) in intervals.iter_mut().enumerate() {
+                let mut values: Vec<F> = replicates.iter().map(|r| r[i]).collect();
+                values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                *interval = (
+                    percentile(&values, alpha),
+                    percentile(&values, F::one() - alpha),
+                );
+            }
+            Ok(intervals)
+        }
+    }
+
+    /// Empirical percentile of a pre-sorted slice: the element at index
+    /// `round(p * (len - 1))`.
+    fn percentile<F: Float>(sorted: &[F], p: F) -> F {
+        let n = sorted.len();
+        let idx = (p * F::from(n - 1).unwrap())
+            .round()
+            .to_usize()
+            .unwrap_or(0)
+            .min(n - 1);
+        sorted[idx]
+    }
+
+    /// Errors produced while estimating model parameters from data, e.g. via
+    /// [`Autoregressive::fit`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum FitError {
+        /// No more data points were supplied than the requested model order.
+        InsufficientData,
+        /// The sample autocovariances were degenerate (e.g. a constant series) or
+        /// estimation otherwise became numerically singular.
+        Degenerate,
+    }
+
+    impl std::fmt::Display for FitError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                FitError::InsufficientData => {
+                    write!(f, "not enough data points to fit the requested model order")
+                }
+                FitError::Degenerate => write!(f, "degenerate or numerically singular input"),
+            }
+        }
+    }
+
+    impl std::error::Error for FitError {}
+
+    impl<F, const N: usize, R> Autoregressive<F, N, rand_distr::Normal<F>, R>
+    where
+        F: Float + std::iter::Sum,
+        StandardNormal: Distribution<F>,
+        R: RngCore,
+    {
+        /// Create a new instance driven by a caller-supplied RNG, e.g. a seeded
+        /// `rand_chacha::ChaCha20Rng` or `rand_pcg::Pcg64`, so the resulting stream is
+        /// reproducible. Innovations are Gaussian, as in [`Self::new`].
+        /// * `c`  parameter
+        /// * `noise_variance` Standard deviation of the white noise (epsilon); it is passed
+        ///   to `rand_distr::Normal` as `std_dev`, so the innovation variance is its square
+        /// * `phi` model parameters
+        /// * `rng` source of randomness for the innovations
+        pub fn new_with_rng(c: F, noise_variance: F, phi: &[F; N], rng: R) -> Self {
             let noise =
                 rand_distr::Normal::new(num_traits::identities::zero(), noise_variance).unwrap();
+            Self::new_with_noise(c, phi, noise, rng)
+        }
+    }
+
+    impl<F, const N: usize, D, R> Autoregressive<F, N, D, R>
+    where
+        F: Float + std::iter::Sum,
+        D: Distribution<F>,
+        R: RngCore,
+    {
+        /// Create a new instance whose innovations `epsilon` are drawn from an arbitrary
+        /// `rand_distr` distribution `D`, e.g. `Cauchy`, `Exponential`, or a Student-t
+        /// distribution, instead of the default Gaussian noise.
+        /// * `c`  parameter
+        /// * `phi` model parameters
+        /// * `noise` distribution the innovations `epsilon` are sampled from
+        /// * `rng` source of randomness for the innovations
+        pub fn new_with_noise(c: F, phi: &[F; N], noise: D, rng: R) -> Self {
+            let x = [num_traits::identities::zero(); N];
             Self {
                 c,
                 phi: *phi,
                 x,
                 noise,
+                rng,
+            }
+        }
+
+        /// The `c` and `phi` parameters currently driving the model, e.g. to inspect the
+        /// result of [`Autoregressive::fit`].
+        pub fn params(&self) -> (F, &[F; N]) {
+            (self.c, &self.phi)
+        }
+
+        /// Whether the AR characteristic polynomial `1 - sum(phi_i * z^i)` has all roots
+        /// outside the unit circle, i.e. whether the process is stationary rather than
+        /// explosive. Returns `None` for `N > 2`, where the condition isn't (yet)
+        /// evaluated; for `N <= 2` it reduces to simple inequalities on `phi`.
+        pub fn is_stationary(&self) -> Option<bool> {
+            match N {
+                0 => Some(true),
+                1 => Some(self.phi[0].abs() < F::one()),
+                2 => {
+                    let phi1 = self.phi[0];
+                    let phi2 = self.phi[1];
+                    Some(phi2 > -F::one() && phi1 + phi2 < F::one() && phi2 - phi1 < F::one())
+                }
+                _ => None,
             }
         }
 
         /// Next value from the AR model
         pub fn step(&mut self) -> F {
-            let mut rng = rand::thread_rng();
-            let epsilon: F = self.noise.sample(&mut rng);
+            let epsilon: F = self.noise.sample(&mut self.rng);
             let new_x = self.c
                 + self
                     .x
@@ -70,10 +297,140 @@ pub mod univariate {
         }
     }
 
-    impl<F, const N: usize> Iterator for Autoregressive<F, N>
+    impl<F, const N: usize, D, R> Iterator for Autoregressive<F, N, D, R>
+    where
+        F: Float + std::iter::Sum,
+        D: Distribution<F>,
+        R: RngCore,
+    {
+        type Item = F;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            Some(self.step())
+        }
+    }
+
+    /// ARMA(`P`, `Q`) model: an AR(`P`) recurrence augmented with a moving-average
+    /// component over the last `Q` innovations, i.e.
+    /// `x_t = c + sum(phi_i * x_{t-i}) + epsilon_t + sum(theta_j * epsilon_{t-j})`.
+    pub struct Arma<F, const P: usize, const Q: usize, D = rand_distr::Normal<F>, R = DefaultRng>
+    where
+        F: Float,
+        D: Distribution<F>,
+    {
+        c: F,
+        x: [F; P],
+        phi: [F; P],
+        eps: [F; Q],
+        theta: [F; Q],
+        noise: D,
+        rng: R,
+    }
+
+    impl<F, const P: usize, const Q: usize> Arma<F, P, Q, rand_distr::Normal<F>, DefaultRng>
+    where
+        F: Float + std::iter::Sum,
+        StandardNormal: Distribution<F>,
+    {
+        /// Create a new instance, sampling Gaussian innovations from the thread-local RNG.
+        /// * `c`  parameter
+        /// * `noise_variance` Standard deviation of the white noise (epsilon); the
+        ///   innovation variance is its square
+        /// * `phi` AR model parameters
+        /// * `theta` MA model parameters
+        pub fn new(c: F, noise_variance: F, phi: &[F; P], theta: &[F; Q]) -> Self {
+            Self::new_with_rng(c, noise_variance, phi, theta, rand::thread_rng())
+        }
+    }
+
+    impl<F, const P: usize, const Q: usize, R> Arma<F, P, Q, rand_distr::Normal<F>, R>
     where
         F: Float + std::iter::Sum,
         StandardNormal: Distribution<F>,
+        R: RngCore,
+    {
+        /// Create a new instance driven by a caller-supplied RNG, so the resulting stream
+        /// is reproducible. Innovations are Gaussian, as in [`Self::new`].
+        /// * `c`  parameter
+        /// * `noise_variance` Standard deviation of the white noise (epsilon); it is passed
+        ///   to `rand_distr::Normal` as `std_dev`, so the innovation variance is its square
+        /// * `phi` AR model parameters
+        /// * `theta` MA model parameters
+        /// * `rng` source of randomness for the innovations
+        pub fn new_with_rng(
+            c: F,
+            noise_variance: F,
+            phi: &[F; P],
+            theta: &[F; Q],
+            rng: R,
+        ) -> Self {
+            let noise =
+                rand_distr::Normal::new(num_traits::identities::zero(), noise_variance).unwrap();
+            Self::new_with_noise(c, phi, theta, noise, rng)
+        }
+    }
+
+    impl<F, const P: usize, const Q: usize, D, R> Arma<F, P, Q, D, R>
+    where
+        F: Float + std::iter::Sum,
+        D: Distribution<F>,
+        R: RngCore,
+    {
+        /// Create a new instance whose innovations `epsilon` are drawn from an arbitrary
+        /// `rand_distr` distribution `D` instead of the default Gaussian noise.
+        /// * `c`  parameter
+        /// * `phi` AR model parameters
+        /// * `theta` MA model parameters
+        /// * `noise` distribution the innovations `epsilon` are sampled from
+        /// * `rng` source of randomness for the innovations
+        pub fn new_with_noise(c: F, phi: &[F; P], theta: &[F; Q], noise: D, rng: R) -> Self {
+            let x = [num_traits::identities::zero(); P];
+            let eps = [num_traits::identities::zero(); Q];
+            Self {
+                c,
+                phi: *phi,
+                x,
+                eps,
+                theta: *theta,
+                noise,
+                rng,
+            }
+        }
+
+        /// Next value from the ARMA model
+        pub fn step(&mut self) -> F {
+            let epsilon: F = self.noise.sample(&mut self.rng);
+            let ar_term = self
+                .x
+                .iter()
+                .zip(self.phi.iter())
+                .map(|(x, p)| *x * *p)
+                .sum::<F>();
+            let ma_term = self
+                .eps
+                .iter()
+                .zip(self.theta.iter())
+                .map(|(e, t)| *e * *t)
+                .sum::<F>();
+            let new_x = self.c + ar_term + epsilon + ma_term;
+
+            if !self.x.is_empty() {
+                self.x.rotate_right(1);
+                self.x[0] = new_x;
+            }
+            if !self.eps.is_empty() {
+                self.eps.rotate_right(1);
+                self.eps[0] = epsilon;
+            }
+            new_x
+        }
+    }
+
+    impl<F, const P: usize, const Q: usize, D, R> Iterator for Arma<F, P, Q, D, R>
+    where
+        F: Float + std::iter::Sum,
+        D: Distribution<F>,
+        R: RngCore,
     {
         type Item = F;
 
@@ -83,6 +440,196 @@ pub mod univariate {
     }
 }
 
+/// Multivariate (vector) AR model
+///
+pub mod multivariate {
+    use num_traits::Float;
+    use rand::RngCore;
+    use rand_distr::{Distribution, StandardNormal};
+
+    use crate::univariate::DefaultRng;
+
+    /// `K`-dimensional, order-`P` vector autoregression: `x_t = c + sum(Phi_i . x_{t-i}) +
+    /// epsilon_t`, where each lag coefficient `Phi_i` is a `K`x`K` matrix and `epsilon_t` is
+    /// drawn from a multivariate normal with covariance `cov`.
+    pub struct VectorAutoregressive<F, const K: usize, const P: usize, R = DefaultRng>
+    where
+        F: Float,
+    {
+        c: [F; K],
+        x: [[F; K]; P],
+        phi: [[[F; K]; K]; P],
+        /// Lower-triangular Cholesky factor of the innovation covariance, so that
+        /// `epsilon = chol . z` for standard normal `z` has covariance `cov`.
+        chol: [[F; K]; K],
+        rng: R,
+    }
+
+    impl<F, const K: usize, const P: usize> VectorAutoregressive<F, K, P, DefaultRng>
+    where
+        F: Float + std::iter::Sum,
+        StandardNormal: Distribution<F>,
+    {
+        /// Create a new instance, sampling from the thread-local RNG.
+        /// * `c` parameter vector
+        /// * `phi` lag coefficient matrices, `phi[i]` is `Phi_{i+1}`
+        /// * `cov` covariance matrix of the innovations
+        pub fn new(c: [F; K], phi: &[[[F; K]; K]; P], cov: &[[F; K]; K]) -> Self {
+            Self::new_with_rng(c, phi, cov, rand::thread_rng())
+        }
+    }
+
+    impl<F, const K: usize, const P: usize, R> VectorAutoregressive<F, K, P, R>
+    where
+        F: Float + std::iter::Sum,
+        StandardNormal: Distribution<F>,
+        R: RngCore,
+    {
+        /// Create a new instance driven by a caller-supplied RNG, so the resulting stream
+        /// is reproducible.
+        /// * `c` parameter vector
+        /// * `phi` lag coefficient matrices, `phi[i]` is `Phi_{i+1}`
+        /// * `cov` covariance matrix of the innovations
+        /// * `rng` source of randomness for the innovations
+        pub fn new_with_rng(
+            c: [F; K],
+            phi: &[[[F; K]; K]; P],
+            cov: &[[F; K]; K],
+            rng: R,
+        ) -> Self {
+            let x = [[F::zero(); K]; P];
+            let chol = cholesky(cov);
+            Self {
+                c,
+                x,
+                phi: *phi,
+                chol,
+                rng,
+            }
+        }
+
+        /// Next value from the VAR model
+        pub fn step(&mut self) -> [F; K] {
+            let z: [F; K] = std::array::from_fn(|_| StandardNormal.sample(&mut self.rng));
+            let epsilon = mat_vec_mul(&self.chol, &z);
+
+            let mut new_x = self.c;
+            for (phi_i, x_i) in self.phi.iter().zip(self.x.iter()) {
+                let contrib = mat_vec_mul(phi_i, x_i);
+                for k in 0..K {
+                    new_x[k] = new_x[k] + contrib[k];
+                }
+            }
+            for k in 0..K {
+                new_x[k] = new_x[k] + epsilon[k];
+            }
+
+            if !self.x.is_empty() {
+                self.x.rotate_right(1);
+                self.x[0] = new_x;
+            }
+            new_x
+        }
+    }
+
+    impl<F, const K: usize, const P: usize, R> Iterator for VectorAutoregressive<F, K, P, R>
+    where
+        F: Float + std::iter::Sum,
+        StandardNormal: Distribution<F>,
+        R: RngCore,
+    {
+        type Item = [F; K];
+
+        fn next(&mut self) -> Option<Self::Item> {
+            Some(self.step())
+        }
+    }
+
+    /// `m . v` for a `K`x`K` matrix `m` and a length-`K` vector `v`.
+    fn mat_vec_mul<F: Float + std::iter::Sum, const K: usize>(
+        m: &[[F; K]; K],
+        v: &[F; K],
+    ) -> [F; K] {
+        std::array::from_fn(|i| m[i].iter().zip(v.iter()).map(|(a, b)| *a * *b).sum::<F>())
+    }
+
+    /// Lower-triangular Cholesky factor `L` of a symmetric positive-definite matrix `cov`,
+    /// such that `L . L^T == cov`.
+    fn cholesky<F: Float + std::iter::Sum, const K: usize>(cov: &[[F; K]; K]) -> [[F; K]; K] {
+        let mut l = [[F::zero(); K]; K];
+        for i in 0..K {
+            for j in 0..=i {
+                let sum = cov[i][j]
+                    - l[i][..j]
+                        .iter()
+                        .zip(l[j][..j].iter())
+                        .map(|(a, b)| *a * *b)
+                        .sum::<F>();
+                if i == j {
+                    l[i][j] = sum.max(F::zero()).sqrt();
+                } else if l[j][j] > F::zero() {
+                    l[i][j] = sum / l[j][j];
+                }
+            }
+        }
+        l
+    }
+}
+
+/// Constant-memory running diagnostics for monitoring a generated stream.
+pub mod diagnostics {
+    use num_traits::Float;
+
+    /// Online mean/variance accumulator using Welford's algorithm, so a generated stream
+    /// can be monitored without collecting it into memory.
+    pub struct Stats<F> {
+        n: u64,
+        avg: F,
+        m2: F,
+    }
+
+    impl<F: Float> Stats<F> {
+        /// Create an empty accumulator.
+        pub fn new() -> Self {
+            Self {
+                n: 0,
+                avg: F::zero(),
+                m2: F::zero(),
+            }
+        }
+
+        /// Fold in one more observation.
+        pub fn update(&mut self, x: F) {
+            self.n += 1;
+            let n = F::from(self.n).unwrap();
+            let delta = x - self.avg;
+            self.avg = self.avg + delta / n;
+            self.m2 = self.m2 + delta * (x - self.avg);
+        }
+
+        /// Running mean of the observations folded in so far.
+        pub fn mean(&self) -> F {
+            self.avg
+        }
+
+        /// Sample variance (Bessel's correction); `None` until at least two observations
+        /// have been folded in.
+        pub fn sample_variance(&self) -> Option<F> {
+            if self.n < 2 {
+                None
+            } else {
+                Some(self.m2 / F::from(self.n - 1).unwrap())
+            }
+        }
+    }
+
+    impl<F: Float> Default for Stats<F> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     #[test]
@@ -109,4 +656,155 @@ mod test {
         let avg = ar.take(NUM).sum::<f32>() / (NUM as f32);
         assert!(avg.abs() < 1.0);
     }
+
+    #[test]
+    fn seeded_is_reproducible() {
+        use rand::SeedableRng;
+
+        let rng = rand_chacha::ChaCha20Rng::seed_from_u64(42);
+        let mut ar =
+            super::univariate::Autoregressive::new_with_rng(0.0, 1.0, &[0.5], rng);
+        let a: Vec<f32> = (&mut ar).take(10).collect();
+
+        let rng = rand_chacha::ChaCha20Rng::seed_from_u64(42);
+        let mut ar =
+            super::univariate::Autoregressive::new_with_rng(0.0, 1.0, &[0.5], rng);
+        let b: Vec<f32> = (&mut ar).take(10).collect();
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn non_gaussian_noise() {
+        const NUM: usize = 1_000_000;
+
+        let noise = rand_distr::Exp::new(1.0f32).unwrap();
+        let ar = super::univariate::Autoregressive::new_with_noise(
+            0.0,
+            &[0.3],
+            noise,
+            rand::thread_rng(),
+        );
+        let avg = ar.take(NUM).sum::<f32>() / (NUM as f32);
+        // Exp(1) has mean 1, so the AR(1) process should settle near 1 / (1 - 0.3).
+        assert!((avg - 1.0 / 0.7).abs() < 0.5);
+    }
+
+    #[test]
+    fn fit_recovers_known_parameters() {
+        const NUM: usize = 1_000_000;
+
+        let ar = super::univariate::Autoregressive::new(1.0, 1.0, &[0.5]);
+        let data: Vec<f64> = ar.take(NUM).collect();
+
+        let fitted = super::univariate::Autoregressive::<f64, 1>::fit(&data).unwrap();
+        let (c, phi) = fitted.params();
+        assert!((phi[0] - 0.5).abs() < 0.05);
+        assert!((c - 1.0).abs() < 0.2);
+    }
+
+    #[test]
+    fn fit_rejects_constant_series() {
+        let data = vec![1.0f32; 100];
+        match super::univariate::Autoregressive::<f32, 1>::fit(&data) {
+            Err(super::univariate::FitError::Degenerate) => {}
+            Ok(_) => panic!("expected Degenerate, got Ok"),
+            Err(e) => panic!("expected Degenerate, got {e:?}"),
+        }
+    }
+
+    #[test]
+    fn arma_bounded() {
+        const NUM: usize = 1_000_000;
+
+        let arma = super::univariate::Arma::new(0.0, 1.0, &[0.3], &[0.2]);
+        let avg = arma.take(NUM).sum::<f32>() / (NUM as f32);
+        assert!(avg.abs() < 1.0);
+    }
+
+    #[test]
+    fn var_bounded() {
+        const NUM: usize = 1_000_000;
+
+        let phi = [[[0.3f32, 0.0], [0.0, 0.3]]];
+        let cov = [[1.0f32, 0.0], [0.0, 1.0]];
+        let var = super::multivariate::VectorAutoregressive::new([0.0, 0.0], &phi, &cov);
+        let sums = var
+            .take(NUM)
+            .fold([0.0f32; 2], |acc, x| [acc[0] + x[0], acc[1] + x[1]]);
+        assert!((sums[0] / NUM as f32).abs() < 1.0);
+        assert!((sums[1] / NUM as f32).abs() < 1.0);
+    }
+
+    #[test]
+    fn stats_matches_known_distribution() {
+        const NUM: usize = 1_000_000;
+
+        let ar = super::univariate::Autoregressive::new(0.0f32, 2.0, &[]);
+        let mut stats = super::diagnostics::Stats::new();
+        for x in ar.take(NUM) {
+            stats.update(x);
+        }
+        assert!(stats.mean().abs() < 0.1);
+        // `noise_variance` is passed straight through as the Normal's standard deviation,
+        // so the true variance is its square.
+        assert!((stats.sample_variance().unwrap() - 4.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn is_stationary() {
+        let ar = super::univariate::Autoregressive::new(0.0, 1.0, &[0.5]);
+        assert_eq!(ar.is_stationary(), Some(true));
+
+        let ar = super::univariate::Autoregressive::new(0.0, 1.0, &[1.5]);
+        assert_eq!(ar.is_stationary(), Some(false));
+
+        let ar = super::univariate::Autoregressive::new(0.0, 1.0, &[0.9, -0.8]);
+        assert_eq!(ar.is_stationary(), Some(true));
+
+        let ar = super::univariate::Autoregressive::new(0.0, 1.0, &[0.5, 0.9]);
+        assert_eq!(ar.is_stationary(), Some(false));
+
+        let ar = super::univariate::Autoregressive::new(0.0, 1.0, &[0.1, 0.1, 0.1]);
+        assert_eq!(ar.is_stationary(), None);
+    }
+
+    #[test]
+    fn bootstrap_ci_covers_true_coefficient() {
+        use rand::SeedableRng;
+
+        const NUM: usize = 10_000;
+
+        let rng = rand_chacha::ChaCha20Rng::seed_from_u64(7);
+        let ar = super::univariate::Autoregressive::new_with_rng(0.0, 1.0, &[0.5], rng);
+        let data: Vec<f64> = ar.take(NUM).collect();
+
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(8);
+        let ci = super::univariate::Autoregressive::<f64, 1>::bootstrap_ci(
+            &data, 200, 0.95, &mut rng,
+        )
+        .unwrap();
+        let (lower, upper) = ci[0];
+        assert!(lower < 0.5 && 0.5 < upper, "0.5 not within ({lower}, {upper})");
+    }
+
+    #[test]
+    fn bootstrap_ci_rejects_empty_replicates() {
+        use rand::SeedableRng;
+
+        const NUM: usize = 1_000;
+
+        let rng = rand_chacha::ChaCha20Rng::seed_from_u64(7);
+        let ar = super::univariate::Autoregressive::new_with_rng(0.0, 1.0, &[0.5], rng);
+        let data: Vec<f64> = ar.take(NUM).collect();
+
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(8);
+        match super::univariate::Autoregressive::<f64, 1>::bootstrap_ci(
+            &data, 0, 0.95, &mut rng,
+        ) {
+            Err(super::univariate::FitError::Degenerate) => {}
+            Ok(_) => panic!("expected Degenerate, got Ok"),
+            Err(e) => panic!("expected Degenerate, got {e:?}"),
+        }
+    }
 }